@@ -1,6 +1,9 @@
 #![deny(warnings)]
 
-use std::{collections::HashMap, hash::Hash};
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
 
 /// Runs an RCIR election, resolving ties by acting equally upon the tied candidates
 ///
@@ -132,6 +135,616 @@ where
     }
 }
 
+/// A strategy for breaking a last-place tie down to a single candidate to
+/// eliminate, for use with [`find_winners_tiebreak`].
+///
+/// [`find_winners`] resolves a last-place tie by eliminating every tied
+/// candidate at once. That can change the outcome versus eliminating one
+/// candidate at a time, so these strategies instead pick a single candidate:
+/// [`TieStrategy::Backwards`] and [`TieStrategy::Forwards`] search the
+/// round-by-round tally history for a round where the tied candidates' counts
+/// differed, and [`TieStrategy::Random`] picks one at random.
+pub enum TieStrategy<R> {
+    /// Eliminate whichever tied candidate had the fewest votes in the most
+    /// recent prior round where the tied candidates' counts differed.
+    Backwards,
+    /// Eliminate whichever tied candidate had the fewest votes in the
+    /// earliest round where the tied candidates' counts differed.
+    Forwards,
+    /// Eliminate a candidate chosen at random among those tied, using the
+    /// given RNG.
+    ///
+    /// Unlike [`TieStrategy::Backwards`] and [`TieStrategy::Forwards`], this
+    /// is used as soon as a tie is detected, without consulting the tally
+    /// history. The RNG picks an index into the tied candidates as listed in
+    /// `HashMap` iteration order, which differs from run to run, so a seeded
+    /// RNG does not by itself make the choice reproducible.
+    Random(R),
+}
+
+/// How many candidates [`find_winners_tiebreak`] eliminates per round when
+/// more than one is tied for last place.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExclusionMode {
+    /// Eliminate every candidate tied for last place at once. This is the
+    /// behavior of [`find_winners`].
+    BatchTied,
+    /// Break the tie first, using the [`TieStrategy`], so that exactly one
+    /// candidate is eliminated per round. Many official IRV rules mandate
+    /// this, since batch elimination can change the winner versus
+    /// eliminating one candidate at a time.
+    SingleLowest,
+}
+
+/// Runs an RCIR election the same way as [`find_winners`], except that
+/// `mode` controls how many candidates are eliminated per round when more
+/// than one is tied for last place; ties that are broken down to a single
+/// candidate are resolved using `strategy`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use rcir::{find_winners_tiebreak, ExclusionMode, TieStrategy};
+/// let ballots = vec![
+///     vec![0],
+///     vec![0],
+///     vec![1, 0],
+///     vec![2, 0],
+/// ];
+/// assert_eq!(
+///     &find_winners_tiebreak(
+///         ballots,
+///         TieStrategy::<rand::rngs::ThreadRng>::Backwards,
+///         ExclusionMode::SingleLowest,
+///     ),
+///     &[0]
+/// );
+/// ```
+pub fn find_winners_tiebreak<I, B, C, R>(
+    ballots: I,
+    mut strategy: TieStrategy<R>,
+    mode: ExclusionMode,
+) -> Vec<C>
+where
+    I: IntoIterator<Item = B>,
+    B: IntoIterator<Item = C>,
+    C: Hash + Eq + Clone,
+    R: rand::Rng,
+{
+    let mut voter_map: HashMap<C, Vec<B::IntoIter>> = HashMap::new();
+    for ballot in ballots {
+        let mut ballot = ballot.into_iter();
+        if let Some(candidate) = ballot.next() {
+            voter_map.entry(candidate).or_default().push(ballot);
+        }
+    }
+
+    // per-round snapshot of every continuing candidate's tally, oldest first,
+    // so tie-breaking can walk backwards or forwards through the count
+    let mut history: Vec<HashMap<C, usize>> = Vec::new();
+
+    loop {
+        let votecounts = voter_map.values().map(|voters| voters.len());
+
+        let best_votecount = votecounts.clone().max();
+        let worst_votecount = votecounts.clone().min();
+
+        let (best_votecount, worst_votecount) = match (best_votecount, worst_votecount) {
+            (Some(b), Some(w)) => (b, w),
+            (None, None) => return Vec::new(),
+            _ => unreachable!("if Iterator::min() returns Some(v), so should Iterator::max()"),
+        };
+
+        let total_votecount: usize = votecounts.sum();
+
+        if best_votecount > total_votecount / 2 {
+            let mut winners = voter_map
+                .into_iter()
+                .filter(|(_candidate, voters)| voters.len() == best_votecount);
+            let (winner, _votecount) = winners
+                .next()
+                .expect("candidate with best votecount to remain in voter map");
+            debug_assert!(winners.next().is_none());
+            return vec![winner];
+        }
+
+        if best_votecount == worst_votecount {
+            let winners = voter_map
+                .into_iter()
+                .map(|(candidate, _voters)| candidate)
+                .collect();
+            return winners;
+        }
+
+        history.push(
+            voter_map
+                .iter()
+                .map(|(candidate, voters)| (candidate.clone(), voters.len()))
+                .collect(),
+        );
+
+        let tied: Vec<C> = voter_map
+            .iter()
+            .filter(|(_candidate, voters)| voters.len() == worst_votecount)
+            .map(|(candidate, _voters)| candidate.clone())
+            .collect();
+
+        let eliminated: Vec<C> = match mode {
+            ExclusionMode::BatchTied => tied,
+            ExclusionMode::SingleLowest => {
+                if tied.len() == 1 {
+                    tied
+                } else {
+                    vec![resolve_tie(tied, &history, &mut strategy)]
+                }
+            }
+        };
+
+        // remove every eliminated candidate before transferring any of their
+        // ballots, so a ballot that ranks two simultaneously-eliminated
+        // candidates back to back skips over both of them
+        let removed: Vec<Vec<B::IntoIter>> = eliminated
+            .into_iter()
+            .map(|candidate| {
+                voter_map
+                    .remove(&candidate)
+                    .expect("eliminated candidate to remain in voter map")
+            })
+            .collect();
+
+        for voters in removed {
+            for mut ballot in voters {
+                while let Some(target) = ballot.next() {
+                    if let Some(voters) = voter_map.get_mut(&target) {
+                        voters.push(ballot);
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Picks a single candidate to eliminate from `tied`, which all have the
+/// current round's worst votecount, using `history` to look for the first
+/// round (scanning in the direction `strategy` prefers) where they differed.
+fn resolve_tie<C: Hash + Eq + Clone, R: rand::Rng>(
+    tied: Vec<C>,
+    history: &[HashMap<C, usize>],
+    strategy: &mut TieStrategy<R>,
+) -> C {
+    let discriminate = |round: &HashMap<C, usize>| -> Option<C> {
+        tied.iter()
+            .min_by_key(|candidate| round.get(candidate))
+            .filter(|candidate| {
+                // only a discriminating round if not every tied candidate
+                // has the same count in it
+                tied.iter()
+                    .any(|other| round.get(other) != round.get(candidate))
+            })
+            .cloned()
+    };
+
+    match strategy {
+        TieStrategy::Backwards => history.iter().rev().find_map(discriminate),
+        TieStrategy::Forwards => history.iter().find_map(discriminate),
+        TieStrategy::Random(_) => None,
+    }
+    .unwrap_or_else(|| match strategy {
+        TieStrategy::Random(rng) => {
+            let index = rng.gen_range(0..tied.len());
+            tied[index].clone()
+        }
+        // no round in the history discriminates between the tied
+        // candidates, so there's no principled choice to make; eliminate
+        // whichever one was encountered first
+        TieStrategy::Backwards | TieStrategy::Forwards => tied[0].clone(),
+    })
+}
+
+/// What, if anything, a [`Round`] decided.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundOutcome {
+    /// A candidate secured a majority of the remaining votes and won outright.
+    Majority,
+    /// Every remaining candidate tied for first place.
+    Tie,
+    /// Neither a majority nor a full tie was reached; the count continues.
+    Continuing,
+}
+
+/// A single round of an RCIR count, as recorded by [`find_winners_detailed`].
+#[derive(Debug, Clone)]
+pub struct Round<C> {
+    /// Every still-standing candidate's tally at the start of this round.
+    pub tallies: Vec<(C, usize)>,
+    /// The candidate(s) eliminated at the end of this round, if any.
+    pub eliminated: Vec<C>,
+    /// Where each eliminated candidate's ballots went, as `(from, to)`
+    /// pairs, in the order the transfers happened. A ballot with no
+    /// continuing candidate left to rank has no entry here.
+    pub transfers: Vec<(C, C)>,
+    /// What this round decided.
+    pub outcome: RoundOutcome,
+}
+
+/// Runs an RCIR election the same way as [`find_winners`], additionally
+/// returning a round-by-round transcript alongside the winners, for
+/// auditability.
+///
+/// Each [`Round`] records every continuing candidate's tally at the start
+/// of the round, which candidate(s) were eliminated, where their ballots
+/// were transferred, and whether the round ended the count.
+///
+/// # Examples
+///
+/// ```rust
+/// # use rcir::find_winners_detailed;
+/// let ballots = vec![vec![0], vec![0], vec![1, 0]];
+/// let (winners, rounds) = find_winners_detailed(ballots);
+/// assert_eq!(&winners, &[0]);
+/// assert_eq!(rounds.len(), 1);
+/// ```
+pub fn find_winners_detailed<I, B, C>(ballots: I) -> (Vec<C>, Vec<Round<C>>)
+where
+    I: IntoIterator<Item = B>,
+    B: IntoIterator<Item = C>,
+    C: Hash + Eq + Clone,
+{
+    let mut voter_map: HashMap<C, Vec<B::IntoIter>> = HashMap::new();
+    for ballot in ballots {
+        let mut ballot = ballot.into_iter();
+        if let Some(candidate) = ballot.next() {
+            voter_map.entry(candidate).or_default().push(ballot);
+        }
+    }
+
+    let mut rounds = Vec::new();
+
+    loop {
+        let tallies: Vec<(C, usize)> = voter_map
+            .iter()
+            .map(|(candidate, voters)| (candidate.clone(), voters.len()))
+            .collect();
+
+        let votecounts = voter_map.values().map(|voters| voters.len());
+
+        let best_votecount = votecounts.clone().max();
+        let worst_votecount = votecounts.clone().min();
+
+        let (best_votecount, worst_votecount) = match (best_votecount, worst_votecount) {
+            (Some(b), Some(w)) => (b, w),
+            (None, None) => return (Vec::new(), rounds),
+            _ => unreachable!("if Iterator::min() returns Some(v), so should Iterator::max()"),
+        };
+
+        let total_votecount: usize = votecounts.sum();
+
+        if best_votecount > total_votecount / 2 {
+            let mut winners = voter_map
+                .into_iter()
+                .filter(|(_candidate, voters)| voters.len() == best_votecount);
+            let (winner, _votecount) = winners
+                .next()
+                .expect("candidate with best votecount to remain in voter map");
+            debug_assert!(winners.next().is_none());
+            rounds.push(Round {
+                tallies,
+                eliminated: Vec::new(),
+                transfers: Vec::new(),
+                outcome: RoundOutcome::Majority,
+            });
+            return (vec![winner], rounds);
+        }
+
+        if best_votecount == worst_votecount {
+            let winners: Vec<C> = voter_map
+                .into_iter()
+                .map(|(candidate, _voters)| candidate)
+                .collect();
+            rounds.push(Round {
+                tallies,
+                eliminated: Vec::new(),
+                transfers: Vec::new(),
+                outcome: RoundOutcome::Tie,
+            });
+            return (winners, rounds);
+        }
+
+        let worst_candidates = hash_map_drain_filter(&mut voter_map, |(_candidate, voters)| {
+            voters.len() == worst_votecount
+        });
+
+        let eliminated: Vec<C> = worst_candidates
+            .iter()
+            .map(|(candidate, _voters)| candidate.clone())
+            .collect();
+        let mut transfers = Vec::new();
+
+        for (candidate, voters) in worst_candidates {
+            for mut ballot in voters {
+                while let Some(target) = ballot.next() {
+                    if let Some(voters) = voter_map.get_mut(&target) {
+                        transfers.push((candidate.clone(), target));
+                        voters.push(ballot);
+                        break;
+                    }
+                }
+            }
+        }
+
+        rounds.push(Round {
+            tallies,
+            eliminated,
+            transfers,
+            outcome: RoundOutcome::Continuing,
+        });
+    }
+}
+
+/// A numeric type usable for ballot weights, tallies and quotas in the
+/// weighted STV count ([`find_winners_stv_with`]).
+///
+/// Implemented for `f64`, which is fast but accumulates floating-point
+/// rounding error across many surplus transfers, and for
+/// [`num_rational::BigRational`], which is exact and so produces
+/// deterministic results independent of summation order, at the cost of
+/// speed. [`find_winners_stv`] is a convenience wrapper fixed to `f64`.
+pub trait Number:
+    Clone
+    + PartialOrd
+    + std::ops::Add<Output = Self>
+    + std::ops::Sub<Output = Self>
+    + std::ops::Mul<Output = Self>
+    + std::ops::Div<Output = Self>
+    + std::iter::Sum
+{
+    /// The additive identity, `0`.
+    fn zero() -> Self;
+    /// Converts a small non-negative integer, such as a ballot count or a
+    /// seat count, into this type.
+    fn from_usize(n: usize) -> Self;
+    /// Rounds down to the nearest whole number representable in this type.
+    fn floor(self) -> Self;
+}
+
+impl Number for f64 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn from_usize(n: usize) -> Self {
+        n as f64
+    }
+
+    fn floor(self) -> Self {
+        f64::floor(self)
+    }
+}
+
+impl Number for num_rational::BigRational {
+    fn zero() -> Self {
+        <Self as num_traits::Zero>::zero()
+    }
+
+    fn from_usize(n: usize) -> Self {
+        num_rational::BigRational::from_integer(n.into())
+    }
+
+    fn floor(self) -> Self {
+        num_rational::BigRational::floor(&self)
+    }
+}
+
+/// Runs a multi-winner Single Transferable Vote election, electing `seats`
+/// candidates, with ballot weights and tallies kept as `f64`.
+///
+/// This is a convenience wrapper around [`find_winners_stv_with`] fixed to
+/// the fast, floating-point [`Number`] implementation; use
+/// `find_winners_stv_with::<_, _, _, num_rational::BigRational>` directly if
+/// exact, reproducible arithmetic matters more than speed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use rcir::find_winners_stv;
+/// // 3 seats, 8 ballots: quota is floor(8 / 4) + 1 = 3
+/// let ballots = vec![
+///     vec![0],
+///     vec![0],
+///     vec![0],
+///     vec![1],
+///     vec![1],
+///     vec![2],
+///     vec![2],
+///     vec![3],
+/// ];
+///
+/// // round 1 elects 0 outright; round 2 eliminates 3 (the unique last
+/// // place); that leaves exactly 2 continuing candidates for the 2
+/// // remaining seats, so 1 and 2 are elected without a further round
+/// let mut winners = find_winners_stv(ballots, 3);
+/// winners.sort();
+/// assert_eq!(&winners, &[0, 1, 2]);
+/// ```
+pub fn find_winners_stv<I, B, C>(ballots: I, seats: usize) -> Vec<C>
+where
+    I: IntoIterator<Item = B>,
+    B: IntoIterator<Item = C>,
+    C: Hash + Eq,
+{
+    find_winners_stv_with::<I, B, C, f64>(ballots, seats)
+}
+
+/// Runs a multi-winner Single Transferable Vote election, electing `seats`
+/// candidates, with ballot weights and tallies kept as the given [`Number`]
+/// type `N`.
+///
+/// Takes an iterator of ballots where each ballot ranks candidates,
+/// starting with first choice and ending with last, same as [`find_winners`].
+///
+/// The Droop quota is computed up front from the number of first-preference
+/// votes. Any round in which a candidate's tally meets or exceeds the quota
+/// elects that candidate; their surplus (tally minus quota) is passed on to
+/// each of their voters' next continuing candidate at a transfer value of
+/// `surplus / tally` (the weighted Gregory method), so a ballot's weight may
+/// become fractional as it passes through one or more elected candidates.
+/// If no candidate reaches the quota, the candidates tied for the fewest votes
+/// are eliminated one at a time and their ballots transfer onward at full
+/// value, stopping early if that would leave fewer continuing candidates than
+/// seats left to fill. The count
+/// stops once `seats` candidates have been elected, or once the number of
+/// continuing candidates drops to the number of seats left to fill, in which
+/// case everyone still standing is elected.
+///
+/// It is a logic error if `ballots` produces more than `usize::MAX` elements,
+/// or if `seats` is greater than the number of distinct candidates.
+pub fn find_winners_stv_with<I, B, C, N>(ballots: I, seats: usize) -> Vec<C>
+where
+    I: IntoIterator<Item = B>,
+    B: IntoIterator<Item = C>,
+    C: Hash + Eq + Clone,
+    N: Number,
+{
+    let mut voter_map: HashMap<C, Vec<WeightedBallot<B::IntoIter, N>>> = HashMap::new();
+    for ballot in ballots {
+        let mut ballot = ballot.into_iter();
+        if let Some(candidate) = ballot.next() {
+            voter_map
+                .entry(candidate)
+                .or_default()
+                .push(WeightedBallot {
+                    iter: ballot,
+                    weight: N::from_usize(1),
+                });
+        }
+    }
+
+    let total_first_prefs: N = voter_map
+        .values()
+        .flatten()
+        .map(|voter| voter.weight.clone())
+        .sum();
+    let quota = (total_first_prefs / N::from_usize(seats + 1)).floor() + N::from_usize(1);
+
+    // candidates already elected or eliminated; a candidate not in here and
+    // not yet a `voter_map` key is still continuing, just without any
+    // votes transferred to them yet
+    let mut decided: HashSet<C> = HashSet::new();
+
+    let mut winners = Vec::new();
+
+    while winners.len() < seats && !voter_map.is_empty() {
+        let remaining_seats = seats - winners.len();
+
+        let elected = hash_map_drain_filter(&mut voter_map, |(_candidate, voters)| {
+            tally(voters) >= quota
+        });
+
+        if !elected.is_empty() {
+            // mark everyone elected this round as decided before moving any
+            // surplus, so no surplus is passed on to a fellow winner
+            for (candidate, _voters) in &elected {
+                decided.insert(candidate.clone());
+            }
+            for (candidate, voters) in elected {
+                let elected_tally = tally(&voters);
+                let surplus = elected_tally.clone() - quota.clone();
+                let transfer_value = surplus / elected_tally;
+                transfer_ballots(&mut voter_map, &decided, voters, transfer_value);
+                winners.push(candidate);
+            }
+            continue;
+        }
+
+        if voter_map.len() <= remaining_seats {
+            // not enough continuing candidates left to justify eliminating
+            // anyone; everyone still standing takes a seat
+            winners.extend(voter_map.into_iter().map(|(candidate, _voters)| candidate));
+            break;
+        }
+
+        // nobody reached quota, eliminate candidates tied for fewest votes,
+        // one at a time, stopping as soon as the number of continuing
+        // candidates would otherwise drop below the seats left to fill
+        let worst_votecount = voter_map
+            .values()
+            .map(|voters| tally(voters))
+            .fold(None, |worst: Option<N>, candidate| match worst {
+                Some(worst) if worst <= candidate => Some(worst),
+                _ => Some(candidate),
+            })
+            .expect("voter_map checked to be non-empty above");
+
+        let tied_candidates: Vec<C> = voter_map
+            .iter()
+            .filter(|(_candidate, voters)| tally(voters) == worst_votecount)
+            .map(|(candidate, _voters)| candidate.clone())
+            .collect();
+
+        for candidate in tied_candidates {
+            if voter_map.len() <= remaining_seats {
+                break;
+            }
+            let voters = voter_map
+                .remove(&candidate)
+                .expect("tied candidate to remain in voter map");
+            decided.insert(candidate);
+            transfer_ballots(&mut voter_map, &decided, voters, N::from_usize(1));
+        }
+    }
+
+    winners
+}
+
+/// A ballot paired with the fractional weight it currently carries.
+///
+/// A ballot starts out at full weight (`1`) and is reduced whenever it
+/// passes through an elected candidate as part of a surplus transfer.
+struct WeightedBallot<I, N> {
+    iter: I,
+    weight: N,
+}
+
+fn tally<I, N: Number>(voters: &[WeightedBallot<I, N>]) -> N {
+    voters.iter().map(|voter| voter.weight.clone()).sum()
+}
+
+/// Transfers `voters` onward to each ballot's next continuing candidate, at
+/// `transfer_value`. A candidate counts as continuing as long as they're not
+/// in `decided` (already elected or eliminated), even if they haven't
+/// received any votes yet; such a candidate gets a fresh `voter_map` entry
+/// the first time a ballot reaches them.
+fn transfer_ballots<C, I, N: Number>(
+    voter_map: &mut HashMap<C, Vec<WeightedBallot<I, N>>>,
+    decided: &HashSet<C>,
+    voters: Vec<WeightedBallot<I, N>>,
+    transfer_value: N,
+) where
+    C: Hash + Eq,
+    I: Iterator<Item = C>,
+{
+    for mut voter in voters {
+        // find next continuing candidate on ballot, if any
+        //
+        // we can't use a for loop here because we reuse the iterator
+        // in the loop body
+        while let Some(target) = voter.iter.next() {
+            if decided.contains(&target) {
+                continue;
+            }
+            voter_map
+                .entry(target)
+                .or_default()
+                .push(WeightedBallot {
+                    iter: voter.iter,
+                    weight: voter.weight * transfer_value.clone(),
+                });
+            break;
+        }
+    }
+}
+
 // FIXME: replace with HashMap::drain_filter once it exists/is stable
 fn hash_map_drain_filter<K: Hash + Eq, V, F: FnMut(&mut (K, V)) -> bool>(
     map: &mut HashMap<K, V>,
@@ -154,6 +767,116 @@ fn hash_map_drain_filter<K: Hash + Eq, V, F: FnMut(&mut (K, V)) -> bool>(
     vec
 }
 
+/// Runs an RCIR election the same way as [`find_winners`], but represents
+/// the candidate-to-voters mapping as a pair of parallel `Vec`s indexed by
+/// candidate number instead of a `HashMap`.
+///
+/// This is an opt-in fast path for elections where candidates are dense,
+/// small, `usize`-like identifiers: tallying, finding the best/worst
+/// votecount, and eliminating candidates become linear scans over
+/// contiguous memory with no hashing, which matters for the `O(V + C^2)`
+/// worst case on large candidate sets. It costs `O(max candidate index)`
+/// space up front to size the vectors, so it's a poor fit for sparse or
+/// large candidate identifiers.
+///
+/// # Examples
+///
+/// ```rust
+/// # use rcir::find_winners_indexed;
+/// let ballots = vec![vec![0usize], vec![0], vec![1, 0]];
+/// assert_eq!(&find_winners_indexed(ballots), &[0]);
+/// ```
+pub fn find_winners_indexed<I, B, C>(ballots: I) -> Vec<C>
+where
+    I: IntoIterator<Item = B>,
+    B: IntoIterator<Item = C>,
+    C: Copy + Into<usize>,
+{
+    // keys[i] holds the original candidate value occupying index i, if any;
+    // values[i] holds that candidate's remaining voters, if any
+    let mut keys: Vec<Option<C>> = Vec::new();
+    let mut values: Vec<Option<Vec<B::IntoIter>>> = Vec::new();
+
+    for ballot in ballots {
+        let mut ballot = ballot.into_iter();
+        if let Some(candidate) = ballot.next() {
+            let index: usize = candidate.into();
+            if index >= values.len() {
+                keys.resize_with(index + 1, || None);
+                values.resize_with(index + 1, || None);
+            }
+            keys[index] = Some(candidate);
+            values[index].get_or_insert_with(Vec::new).push(ballot);
+        }
+    }
+
+    loop {
+        let votecounts = values.iter().filter_map(|voters| voters.as_ref().map(Vec::len));
+
+        let best_votecount = votecounts.clone().max();
+        let worst_votecount = votecounts.clone().min();
+
+        let (best_votecount, worst_votecount) = match (best_votecount, worst_votecount) {
+            (Some(b), Some(w)) => (b, w),
+            // no voters present, return empty list
+            (None, None) => return Vec::new(),
+            _ => unreachable!("if Iterator::min() returns Some(v), so should Iterator::max()"),
+        };
+
+        // here we assume overflow doesn't occur, see the `find_winners` docs
+        let total_votecount: usize = votecounts.sum();
+
+        if best_votecount > total_votecount / 2 {
+            // a candidate has the remaining majority, return a single winner
+            let index = values
+                .iter()
+                .position(|voters| matches!(voters, Some(v) if v.len() == best_votecount))
+                .expect("candidate with best votecount to remain indexed");
+            let winner = keys[index].expect("indexed candidate to have a key");
+            return vec![winner];
+        }
+
+        if best_votecount == worst_votecount {
+            // all the remaining candidates are tied, return a tie between them
+            return keys.iter().copied().flatten().collect();
+        }
+
+        // nobody has won yet, eliminate all the candidates tied for worst;
+        // remove every one of them before transferring any of their
+        // ballots, so a ballot that ranks two simultaneously-eliminated
+        // candidates back to back skips over both of them
+        let worst_indices: Vec<usize> = (0..values.len())
+            .filter(|&index| matches!(&values[index], Some(v) if v.len() == worst_votecount))
+            .collect();
+
+        let removed: Vec<Vec<B::IntoIter>> = worst_indices
+            .into_iter()
+            .map(|index| {
+                keys[index] = None;
+                values[index]
+                    .take()
+                    .expect("index just checked to be occupied")
+            })
+            .collect();
+
+        for voters in removed {
+            for mut ballot in voters {
+                // find next remaining candidate on ballot, if any
+                //
+                // we can't use a for loop here because we reuse the iterator
+                // in the loop body
+                while let Some(target) = ballot.next() {
+                    let target_index: usize = target.into();
+                    if let Some(Some(target_voters)) = values.get_mut(target_index) {
+                        target_voters.push(ballot);
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::collections::HashSet;
@@ -161,7 +884,10 @@ mod test {
     #[allow(unused_imports)]
     use self::iter_assert::{deplete, neutral, undeplete};
 
-    use super::find_winners;
+    use super::{
+        find_winners, find_winners_detailed, find_winners_indexed, find_winners_stv,
+        find_winners_stv_with, find_winners_tiebreak, ExclusionMode, RoundOutcome, TieStrategy,
+    };
 
     #[test]
     fn remaining_majority_wins() {
@@ -339,6 +1065,248 @@ mod test {
         );
     }
 
+    #[test]
+    fn stv_with_exact_rational_weights_agrees_with_f64() {
+        // quota is floor(6 / 3) + 1 = 3
+        let ballots = vec![
+            vec![0],
+            vec![0],
+            vec![0],
+            vec![0],
+            vec![0, 1],
+            vec![2],
+        ];
+        let mut float_winners = find_winners_stv_with::<_, _, _, f64>(ballots.clone(), 2);
+        float_winners.sort();
+
+        let mut exact_winners =
+            find_winners_stv_with::<_, _, _, num_rational::BigRational>(ballots, 2);
+        exact_winners.sort();
+
+        assert_eq!(float_winners, exact_winners);
+    }
+
+    #[test]
+    fn stv_elects_first_round_quota_winners() {
+        // quota is floor(7 / 3) + 1 = 3
+        let ballots = vec![
+            vec![0],
+            vec![0],
+            vec![0],
+            vec![1],
+            vec![1],
+            vec![1],
+            vec![2],
+        ];
+        let mut winners = find_winners_stv(ballots, 2);
+        winners.sort();
+        assert_eq!(&winners, &[0, 1]);
+    }
+
+    #[test]
+    fn stv_transfers_surplus_to_next_preference() {
+        // quota is floor(6 / 3) + 1 = 3
+        let ballots = vec![
+            vec![0, 1],
+            vec![0, 1],
+            vec![0, 1],
+            vec![0, 1],
+            vec![0],
+            vec![2],
+        ];
+        // 1 never receives a first-preference vote, so it only becomes a
+        // continuing candidate once 0's surplus of 2 (5 votes - quota 3)
+        // transfers onward at 2/5 value; four of 0's five ballots carry a
+        // preference for 1, giving 1 a tally of 1.6, ahead of 2's single
+        // vote. That makes 2 the one eliminated, and with one seat left and
+        // one continuing candidate, 1 takes it.
+        let mut winners = find_winners_stv(ballots, 2);
+        winners.sort();
+        assert_eq!(&winners, &[0, 1]);
+    }
+
+    #[test]
+    fn stv_eliminates_lowest_when_nobody_meets_quota() {
+        // quota is floor(6 / 3) + 1 = 3
+        let ballots = vec![
+            vec![0],
+            vec![0],
+            vec![1],
+            vec![1],
+            vec![2, 0],
+            vec![3, 1],
+        ];
+        let mut winners = find_winners_stv(ballots, 2);
+        winners.sort();
+        assert_eq!(&winners, &[0, 1]);
+    }
+
+    #[test]
+    fn stv_stops_batch_tied_elimination_once_seats_equal_continuing_candidates() {
+        // quota is floor(7 / 4) + 1 = 2
+        let ballots = vec![vec![0], vec![0], vec![0], vec![1], vec![1], vec![2], vec![3]];
+        // 0 and 1 are elected in round 1; that leaves candidates 2 and 3
+        // tied for last with one seat left to fill, so only one of them
+        // may be eliminated before the remaining-seats shortcut kicks in
+        let winners = find_winners_stv(ballots, 3);
+        assert_eq!(winners.len(), 3);
+        assert!(winners.contains(&0));
+        assert!(winners.contains(&1));
+        assert!(winners.contains(&2) || winners.contains(&3));
+    }
+
+    #[test]
+    fn stv_fills_remaining_seats_once_continuing_equals_seats_left() {
+        let ballots = vec![vec![0], vec![1], vec![2]];
+        let mut winners = find_winners_stv(ballots, 3);
+        winners.sort();
+        assert_eq!(&winners, &[0, 1, 2]);
+    }
+
+    #[test]
+    fn detailed_majority_produces_single_round() {
+        let ballots = vec![undeplete(vec![0]), undeplete(vec![0]), undeplete(vec![1, 0])];
+        let (winners, rounds) = find_winners_detailed(ballots);
+        assert_eq!(&winners, &[0]);
+        assert_eq!(rounds.len(), 1);
+        assert_eq!(rounds[0].outcome, RoundOutcome::Majority);
+        assert!(rounds[0].eliminated.is_empty());
+    }
+
+    #[test]
+    fn detailed_mutual_elimination_takes_two_rounds() {
+        // 0 has 2 of 5 first-preference votes, short of a majority, so round
+        // 1 eliminates the 3-way tie for worst (1, 2, and 3, each with a
+        // single vote); every one of their ballots ranks only other
+        // eliminated-this-round candidates next, so none of them transfer
+        // anywhere, leaving 0 with an unchanged but now-majority 2 of 2
+        let ballots = vec![
+            undeplete(vec![0]),
+            undeplete(vec![0]),
+            deplete(vec![2, 1]),
+            deplete(vec![3, 2]),
+            deplete(vec![1, 3]),
+        ];
+        let (winners, rounds) = find_winners_detailed(ballots);
+        assert_eq!(&winners, &[0]);
+        assert_eq!(rounds.len(), 2);
+        let mut eliminated = rounds[0].eliminated.clone();
+        eliminated.sort();
+        assert_eq!(&eliminated, &[1, 2, 3]);
+        assert!(rounds[0].transfers.is_empty());
+        assert_eq!(rounds[0].outcome, RoundOutcome::Continuing);
+        assert_eq!(rounds[1].outcome, RoundOutcome::Majority);
+    }
+
+    #[test]
+    fn detailed_records_eliminations_and_transfers() {
+        let ballots = vec![
+            undeplete(vec![0]),
+            undeplete(vec![0]),
+            undeplete(vec![1]),
+            undeplete(vec![1]),
+            undeplete(vec![2, 0]),
+        ];
+        let (winners, rounds) = find_winners_detailed(ballots);
+        assert_eq!(&winners, &[0]);
+        assert_eq!(rounds.len(), 2);
+        assert_eq!(rounds[0].outcome, RoundOutcome::Continuing);
+        assert_eq!(&rounds[0].eliminated, &[2]);
+        assert_eq!(&rounds[0].transfers, &[(2, 0)]);
+        assert_eq!(rounds[1].outcome, RoundOutcome::Majority);
+    }
+
+    #[test]
+    fn detailed_full_tie_produces_tie_outcome() {
+        let ballots = vec![undeplete(vec![0]), undeplete(vec![1])];
+        let (mut winners, rounds) = find_winners_detailed(ballots);
+        winners.sort();
+        assert_eq!(&winners, &[0, 1]);
+        assert_eq!(rounds.len(), 1);
+        assert_eq!(rounds[0].outcome, RoundOutcome::Tie);
+    }
+
+    fn tiebreak_ballots() -> Vec<Vec<u32>> {
+        vec![
+            vec![0],
+            vec![0],
+            vec![0],
+            vec![0],
+            vec![1, 0],
+            vec![1, 0],
+            vec![1, 0],
+            vec![2, 3, 1, 0],
+            vec![3, 1, 0],
+            vec![3, 1, 0],
+        ]
+    }
+
+    // batch-eliminating the round-two tie between candidates 1 and 3 leaves
+    // only candidate 0 standing, while breaking it down to a single
+    // elimination (using the round-one history to see that 3 trailed 1)
+    // instead sends 3's ballots on to 1, who then wins outright
+    #[test]
+    fn batch_tied_and_single_lowest_can_disagree() {
+        assert_eq!(
+            &find_winners_tiebreak(
+                tiebreak_ballots(),
+                TieStrategy::<rand::rngs::mock::StepRng>::Backwards,
+                ExclusionMode::BatchTied,
+            ),
+            &[0]
+        );
+
+        assert_eq!(
+            &find_winners_tiebreak(
+                tiebreak_ballots(),
+                TieStrategy::<rand::rngs::mock::StepRng>::Backwards,
+                ExclusionMode::SingleLowest,
+            ),
+            &[1]
+        );
+    }
+
+    #[test]
+    fn indexed_remaining_majority_wins() {
+        let ballots = vec![
+            undeplete(vec![0usize]),
+            undeplete(vec![0]),
+            deplete(vec![2, 1]),
+            deplete(vec![3, 2]),
+            deplete(vec![1, 3]),
+        ];
+        assert_eq!(&find_winners_indexed(ballots), &[0]);
+    }
+
+    #[test]
+    fn indexed_ties_work() {
+        let ballots = vec![
+            undeplete(vec![0usize]),
+            undeplete(vec![0]),
+            undeplete(vec![1]),
+            undeplete(vec![1]),
+        ];
+        assert_eq!(
+            find_winners_indexed(ballots).iter().collect::<HashSet<_>>(),
+            [0, 1].iter().collect::<HashSet<_>>()
+        );
+    }
+
+    #[test]
+    fn indexed_sparse_candidate_numbers_work() {
+        // 100 never receives a first-preference vote, so it never gets a slot
+        // in the index vectors; transfers towards it look up an index past
+        // their end and skip over it
+        let ballots = vec![
+            undeplete(vec![0usize]),
+            undeplete(vec![0]),
+            deplete(vec![1, 100]),
+            deplete(vec![2, 100]),
+            deplete(vec![3, 100]),
+        ];
+        assert_eq!(&find_winners_indexed(ballots), &[0]);
+    }
+
     // iterator testing helper functions to assert how far they will be polled
     mod iter_assert {
         use std::fmt::Debug;